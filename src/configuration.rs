@@ -1,5 +1,6 @@
 use std::slice;
 
+use ::descriptor;
 use ::interface::Interface;
 
 
@@ -10,7 +11,8 @@ pub struct Configuration {
     description_index: Option<u8>,
     attributes: u8,
     max_power: u8,
-    interfaces: Vec<Interface>
+    interfaces: Vec<Interface>,
+    extra: Vec<u8>
 }
 
 impl Configuration {
@@ -43,12 +45,63 @@ impl Configuration {
     pub fn interfaces(&self) -> &[Interface] {
         &self.interfaces[..]
     }
+
+    /// Returns the raw class- or vendor-specific descriptor bytes that follow the standard
+    /// configuration descriptor.
+    pub fn extra(&self) -> &[u8] {
+        &self.extra[..]
+    }
+
+    /// Returns the configuration's interfaces grouped into logical functions.
+    ///
+    /// Composite devices (e.g. CDC-ACM, UVC) group several interfaces into a single function
+    /// using an Interface Association Descriptor in the configuration's `extra()` bytes.
+    /// Interfaces that aren't covered by a (valid) association still appear, each as its own
+    /// single-interface function. The `extra` bytes come from the device, so an IAD reporting a
+    /// `bFirstInterface`/`bInterfaceCount` that doesn't match a contiguous run of interface
+    /// numbers actually present is ignored rather than trusted.
+    pub fn functions(&self) -> Vec<&[Interface]> {
+        let associations = descriptor::descriptors(&self.extra).filter_map(|d| d.interface_association());
+        let mut covered = vec![false; self.interfaces.len()];
+        let mut functions = Vec::new();
+
+        for association in associations {
+            let first = association.first_interface() as usize;
+            let count = association.interface_count() as usize;
+
+            if count == 0 {
+                continue;
+            }
+
+            let start = self.interfaces.iter().position(|interface| interface.number() as usize == first);
+
+            let group = start
+                .and_then(|start| self.interfaces.get(start..start + count))
+                .filter(|group| group.iter().enumerate().all(|(offset, interface)| interface.number() as usize == first + offset));
+
+            if let (Some(start), Some(group)) = (start, group) {
+                for covered in &mut covered[start..start + count] {
+                    *covered = true;
+                }
+
+                functions.push(group);
+            }
+        }
+
+        functions.extend(
+            self.interfaces.iter().enumerate()
+                .filter(|&(index, _)| !covered[index])
+                .map(|(_, interface)| slice::from_ref(interface)));
+
+        functions
+    }
 }
 
 
 #[doc(hidden)]
 pub fn from_libusb(configuration: &::libusb::libusb_config_descriptor) -> Configuration {
     let interfaces = unsafe { slice::from_raw_parts(configuration.interface, configuration.bNumInterfaces as usize) };
+    let extra = unsafe { slice::from_raw_parts(configuration.extra, configuration.extra_length as usize) };
 
     Configuration {
         number:            configuration.bConfigurationValue,
@@ -58,7 +111,8 @@ pub fn from_libusb(configuration: &::libusb::libusb_config_descriptor) -> Config
         },
         attributes:        configuration.bmAttributes,
         max_power:         configuration.bMaxPower,
-        interfaces:        interfaces.iter().map(|interface| ::interface::from_libusb(&interface)).collect()
+        interfaces:        interfaces.iter().map(|interface| ::interface::from_libusb(&interface)).collect(),
+        extra:             extra.to_vec()
     }
 }
 
@@ -111,4 +165,111 @@ mod test {
             vec!(&::interface::from_libusb(&interface)),
             ::configuration::from_libusb(&config_descriptor!(interface)).interfaces().iter().collect::<Vec<_>>());
     }
+
+    #[test]
+    fn it_has_extra() {
+        assert_eq!(
+            &[0x03, 0x0b, 0x00, 0x01][..],
+            ::configuration::from_libusb(&config_descriptor!(extra: &[0x03, 0x0b, 0x00, 0x01])).extra());
+    }
+
+    #[test]
+    fn it_handles_missing_extra() {
+        assert_eq!(&[] as &[u8], ::configuration::from_libusb(&config_descriptor!()).extra());
+    }
+
+    #[test]
+    fn it_falls_back_to_one_interface_per_function_without_an_iad() {
+        let interface1 = interface!(interface_descriptor!(bInterfaceNumber: 0));
+        let interface2 = interface!(interface_descriptor!(bInterfaceNumber: 1));
+
+        let configuration = ::configuration::from_libusb(&config_descriptor!(interface1, interface2));
+
+        assert_eq!(
+            vec!(&configuration.interfaces()[0..1], &configuration.interfaces()[1..2]),
+            configuration.functions());
+    }
+
+    #[test]
+    fn it_groups_interfaces_by_interface_association_descriptor() {
+        let interface1 = interface!(interface_descriptor!(bInterfaceNumber: 0));
+        let interface2 = interface!(interface_descriptor!(bInterfaceNumber: 1));
+        let iad = [0x08, 0x0b, 0, 2, 0, 0, 0, 0];
+
+        let configuration = ::configuration::from_libusb(&config_descriptor!(interface1, interface2, extra: &iad));
+
+        assert_eq!(vec!(configuration.interfaces()), configuration.functions());
+    }
+
+    #[test]
+    fn it_keeps_interfaces_not_covered_by_an_iad_as_their_own_functions() {
+        let interface1 = interface!(interface_descriptor!(bInterfaceNumber: 0));
+        let interface2 = interface!(interface_descriptor!(bInterfaceNumber: 1));
+        let interface3 = interface!(interface_descriptor!(bInterfaceNumber: 2));
+        let iad = [0x08, 0x0b, 0, 2, 0, 0, 0, 0];
+
+        let configuration =
+            ::configuration::from_libusb(&config_descriptor!(interface1, interface2, interface3, extra: &iad));
+
+        assert_eq!(
+            vec!(&configuration.interfaces()[0..2], &configuration.interfaces()[2..3]),
+            configuration.functions());
+    }
+
+    #[test]
+    fn it_ignores_an_iad_whose_range_overruns_the_interface_list() {
+        let interface1 = interface!(interface_descriptor!(bInterfaceNumber: 0));
+        let interface2 = interface!(interface_descriptor!(bInterfaceNumber: 1));
+        let iad = [0x08, 0x0b, 0, 5, 0, 0, 0, 0];
+
+        let configuration = ::configuration::from_libusb(&config_descriptor!(interface1, interface2, extra: &iad));
+
+        assert_eq!(
+            vec!(&configuration.interfaces()[0..1], &configuration.interfaces()[1..2]),
+            configuration.functions());
+    }
+
+    #[test]
+    fn it_ignores_an_iad_whose_first_interface_is_not_present() {
+        let interface1 = interface!(interface_descriptor!(bInterfaceNumber: 0));
+        let interface2 = interface!(interface_descriptor!(bInterfaceNumber: 1));
+        let iad = [0x08, 0x0b, 9, 2, 0, 0, 0, 0];
+
+        let configuration = ::configuration::from_libusb(&config_descriptor!(interface1, interface2, extra: &iad));
+
+        assert_eq!(
+            vec!(&configuration.interfaces()[0..1], &configuration.interfaces()[1..2]),
+            configuration.functions());
+    }
+
+    #[test]
+    fn it_ignores_an_iad_with_a_zero_interface_count() {
+        let interface1 = interface!(interface_descriptor!(bInterfaceNumber: 0));
+        let interface2 = interface!(interface_descriptor!(bInterfaceNumber: 1));
+        let iad = [0x08, 0x0b, 0, 0, 0, 0, 0, 0];
+
+        let configuration = ::configuration::from_libusb(&config_descriptor!(interface1, interface2, extra: &iad));
+
+        assert_eq!(
+            vec!(&configuration.interfaces()[0..1], &configuration.interfaces()[1..2]),
+            configuration.functions());
+    }
+
+    #[test]
+    fn it_does_not_overflow_near_the_top_of_the_interface_number_range() {
+        let interface1 = interface!(interface_descriptor!(bInterfaceNumber: 254));
+        let interface2 = interface!(interface_descriptor!(bInterfaceNumber: 255));
+        let interface3 = interface!(interface_descriptor!(bInterfaceNumber: 0));
+        let iad = [0x08, 0x0b, 254, 3, 0, 0, 0, 0];
+
+        let configuration =
+            ::configuration::from_libusb(&config_descriptor!(interface1, interface2, interface3, extra: &iad));
+
+        assert_eq!(
+            vec!(
+                &configuration.interfaces()[0..1],
+                &configuration.interfaces()[1..2],
+                &configuration.interfaces()[2..3]),
+            configuration.functions());
+    }
 }
@@ -0,0 +1,176 @@
+/// The descriptor type code for an Interface Association Descriptor.
+pub const DESCRIPTOR_TYPE_INTERFACE_ASSOCIATION: u8 = 0x0b;
+
+
+/// A single TLV-style descriptor record borrowed from a raw descriptor blob, such as a
+/// configuration's `extra()` bytes.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct DescriptorRef<'a> {
+    descriptor_type: u8,
+    bytes: &'a [u8]
+}
+
+impl<'a> DescriptorRef<'a> {
+    /// Returns the descriptor's `bDescriptorType`.
+    pub fn descriptor_type(&self) -> u8 {
+        self.descriptor_type
+    }
+
+    /// Returns the descriptor's raw bytes, including the `bLength`/`bDescriptorType` header.
+    pub fn bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Interprets this descriptor as an Interface Association Descriptor, if its type matches and
+    /// it is at least 8 bytes long.
+    pub fn interface_association(&self) -> Option<InterfaceAssociationDescriptor<'a>> {
+        if self.descriptor_type == DESCRIPTOR_TYPE_INTERFACE_ASSOCIATION && self.bytes.len() >= 8 {
+            Some(InterfaceAssociationDescriptor { bytes: self.bytes })
+        } else {
+            None
+        }
+    }
+}
+
+
+/// An Interface Association Descriptor (IAD), which groups a run of contiguous interfaces into a
+/// single logical function on a composite device.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct InterfaceAssociationDescriptor<'a> {
+    bytes: &'a [u8]
+}
+
+impl<'a> InterfaceAssociationDescriptor<'a> {
+    /// Returns the interface number of the first interface associated with the function.
+    pub fn first_interface(&self) -> u8 {
+        self.bytes[2]
+    }
+
+    /// Returns the number of contiguous interfaces associated with the function.
+    pub fn interface_count(&self) -> u8 {
+        self.bytes[3]
+    }
+
+    /// Returns the function's class code.
+    pub fn function_class(&self) -> u8 {
+        self.bytes[4]
+    }
+
+    /// Returns the function's subclass code.
+    pub fn function_sub_class(&self) -> u8 {
+        self.bytes[5]
+    }
+
+    /// Returns the function's protocol code.
+    pub fn function_protocol(&self) -> u8 {
+        self.bytes[6]
+    }
+
+    /// Returns the index of the string descriptor that describes the function.
+    pub fn function_string_index(&self) -> Option<u8> {
+        match self.bytes[7] {
+            0 => None,
+            n => Some(n)
+        }
+    }
+}
+
+
+/// Iterates over the TLV-style descriptor records in a raw descriptor blob.
+///
+/// Each record starts with `bLength` (the record's total length, including these two header
+/// bytes) followed by `bDescriptorType`. Iteration stops when fewer than two bytes remain, or
+/// when a record's `bLength` is less than two or overruns the remaining buffer.
+pub struct Descriptors<'a> {
+    bytes: &'a [u8]
+}
+
+impl<'a> Iterator for Descriptors<'a> {
+    type Item = DescriptorRef<'a>;
+
+    fn next(&mut self) -> Option<DescriptorRef<'a>> {
+        if self.bytes.len() < 2 {
+            return None;
+        }
+
+        let length = self.bytes[0] as usize;
+
+        if length < 2 || length > self.bytes.len() {
+            return None;
+        }
+
+        let (record, rest) = self.bytes.split_at(length);
+
+        self.bytes = rest;
+
+        Some(DescriptorRef { descriptor_type: record[1], bytes: record })
+    }
+}
+
+/// Returns an iterator over the descriptor records in a raw descriptor blob.
+pub fn descriptors(bytes: &[u8]) -> Descriptors {
+    Descriptors { bytes: bytes }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_iterates_over_descriptors() {
+        let bytes = [0x03, 0xff, 0xaa, 0x04, 0xfe, 0xbb, 0xcc];
+        let found: Vec<_> = descriptors(&bytes).map(|d| (d.descriptor_type(), d.bytes())).collect();
+
+        assert_eq!(vec!((0xff, &bytes[0..3]), (0xfe, &bytes[3..7])), found);
+    }
+
+    #[test]
+    fn it_stops_on_a_trailing_partial_record() {
+        let bytes = [0x03, 0xff, 0xaa, 0x01];
+
+        assert_eq!(1, descriptors(&bytes).count());
+    }
+
+    #[test]
+    fn it_stops_on_a_zero_length_record() {
+        let bytes = [0x00, 0xff, 0xaa];
+
+        assert_eq!(0, descriptors(&bytes).count());
+    }
+
+    #[test]
+    fn it_stops_on_a_record_that_overruns_the_buffer() {
+        let bytes = [0x09, 0xff, 0xaa];
+
+        assert_eq!(0, descriptors(&bytes).count());
+    }
+
+    #[test]
+    fn it_recognizes_an_interface_association_descriptor() {
+        let bytes = [0x08, 0x0b, 1, 2, 3, 4, 5, 6];
+        let iad = descriptors(&bytes).next().unwrap().interface_association().unwrap();
+
+        assert_eq!(1, iad.first_interface());
+        assert_eq!(2, iad.interface_count());
+        assert_eq!(3, iad.function_class());
+        assert_eq!(4, iad.function_sub_class());
+        assert_eq!(5, iad.function_protocol());
+        assert_eq!(Some(6), iad.function_string_index());
+    }
+
+    #[test]
+    fn it_handles_a_missing_function_string_index() {
+        let bytes = [0x08, 0x0b, 1, 2, 3, 4, 5, 0];
+        let iad = descriptors(&bytes).next().unwrap().interface_association().unwrap();
+
+        assert_eq!(None, iad.function_string_index());
+    }
+
+    #[test]
+    fn it_rejects_other_descriptor_types_as_an_interface_association() {
+        let bytes = [0x08, 0x04, 1, 2, 3, 4, 5, 6];
+
+        assert_eq!(None, descriptors(&bytes).next().unwrap().interface_association());
+    }
+}
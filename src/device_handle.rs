@@ -0,0 +1,98 @@
+use ::configuration::Configuration;
+use ::interface_descriptor::InterfaceDescriptor;
+
+
+const CONTROL_REQUEST_TYPE_STANDARD_DEVICE_TO_HOST: u8 = 0x80;
+const LIBUSB_REQUEST_GET_DESCRIPTOR: u8 = 0x06;
+const LIBUSB_DESCRIPTOR_TYPE_STRING: u16 = 0x03;
+const CONTROL_TRANSFER_TIMEOUT_MS: u32 = 1000;
+
+
+/// A handle to an open USB device.
+pub struct DeviceHandle {
+    handle: *mut ::libusb::libusb_device_handle
+}
+
+impl DeviceHandle {
+    /// Reads the string descriptor at `index`, if any, decoded using the device's first
+    /// supported language.
+    ///
+    /// This performs the standard two-step lookup: the list of supported LANGIDs is read from
+    /// string descriptor zero, then the target descriptor is re-read using the first LANGID and
+    /// its UTF-16LE payload is decoded.
+    pub fn read_string_descriptor(&self, index: u8) -> Option<String> {
+        self.read_first_language().and_then(|language| self.read_string(index, language))
+    }
+
+    /// Reads the configuration's string descriptor, if it has one.
+    pub fn read_configuration_string(&self, configuration: &Configuration) -> Option<String> {
+        configuration.description_string_index().and_then(|index| self.read_string_descriptor(index))
+    }
+
+    /// Reads an alternate setting's string descriptor, if it has one.
+    pub fn read_interface_string(&self, descriptor: &InterfaceDescriptor) -> Option<String> {
+        descriptor.description_string_index().and_then(|index| self.read_string_descriptor(index))
+    }
+
+    /// Reads a device-level string descriptor (e.g. `iManufacturer`, `iProduct`, or
+    /// `iSerialNumber` from the device descriptor), if `index` is non-zero.
+    pub fn read_device_string(&self, index: u8) -> Option<String> {
+        match index {
+            0 => None,
+            n => self.read_string_descriptor(n)
+        }
+    }
+
+    fn read_first_language(&self) -> Option<u16> {
+        let mut buffer = [0u8; 255];
+
+        match self.read_descriptor(0, 0, &mut buffer) {
+            Some(length) if length >= 4 => Some(u16::from(buffer[2]) | (u16::from(buffer[3]) << 8)),
+            _ => None
+        }
+    }
+
+    fn read_string(&self, index: u8, language_id: u16) -> Option<String> {
+        let mut buffer = [0u8; 255];
+
+        match self.read_descriptor(index, language_id, &mut buffer) {
+            Some(length) if length >= 2 => {
+                let code_units: Vec<u16> = buffer[2..length].chunks(2)
+                    .map(|chunk| u16::from(chunk[0]) | (u16::from(*chunk.get(1).unwrap_or(&0)) << 8))
+                    .collect();
+
+                Some(String::from_utf16_lossy(&code_units))
+            },
+            _ => None
+        }
+    }
+
+    fn read_descriptor(&self, descriptor_index: u8, language_id: u16, buffer: &mut [u8]) -> Option<usize> {
+        let value = (LIBUSB_DESCRIPTOR_TYPE_STRING << 8) | descriptor_index as u16;
+
+        let result = unsafe {
+            ::libusb::libusb_control_transfer(
+                self.handle,
+                CONTROL_REQUEST_TYPE_STANDARD_DEVICE_TO_HOST,
+                LIBUSB_REQUEST_GET_DESCRIPTOR,
+                value,
+                language_id,
+                buffer.as_mut_ptr(),
+                buffer.len() as u16,
+                CONTROL_TRANSFER_TIMEOUT_MS
+            )
+        };
+
+        if result < 0 {
+            None
+        } else {
+            Some(result as usize)
+        }
+    }
+}
+
+
+#[doc(hidden)]
+pub fn from_libusb(handle: *mut ::libusb::libusb_device_handle) -> DeviceHandle {
+    DeviceHandle { handle: handle }
+}
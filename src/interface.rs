@@ -0,0 +1,57 @@
+use std::slice;
+
+use ::interface_descriptor::InterfaceDescriptor;
+
+
+/// Describes an interface and all of its alternate settings.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Interface {
+    number: u8,
+    descriptors: Vec<InterfaceDescriptor>
+}
+
+impl Interface {
+    /// Returns the interface's number.
+    pub fn number(&self) -> u8 {
+        self.number
+    }
+
+    /// Returns a collection of the interface's alternate settings.
+    pub fn descriptors(&self) -> &[InterfaceDescriptor] {
+        &self.descriptors[..]
+    }
+}
+
+
+#[doc(hidden)]
+pub fn from_libusb(interface: &::libusb::libusb_interface) -> Interface {
+    let altsettings = unsafe { slice::from_raw_parts(interface.altsetting, interface.num_altsetting as usize) };
+    let descriptors: Vec<_> = altsettings.iter().map(|descriptor| ::interface_descriptor::from_libusb(descriptor)).collect();
+
+    Interface {
+        number:      descriptors[0].interface_number(),
+        descriptors: descriptors
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn it_has_number() {
+        let interface = interface!(interface_descriptor!(bInterfaceNumber: 42));
+
+        assert_eq!(42, ::interface::from_libusb(&interface).number());
+    }
+
+    #[test]
+    fn it_has_descriptors_for_every_alternate_setting() {
+        let descriptor1 = interface_descriptor!(bInterfaceNumber: 1, bAlternateSetting: 0);
+        let descriptor2 = interface_descriptor!(bInterfaceNumber: 1, bAlternateSetting: 1);
+        let interface = interface!(descriptor1, descriptor2);
+
+        assert_eq!(
+            vec!(::interface_descriptor::from_libusb(&descriptor1), ::interface_descriptor::from_libusb(&descriptor2)),
+            ::interface::from_libusb(&interface).descriptors());
+    }
+}
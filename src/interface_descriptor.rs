@@ -0,0 +1,97 @@
+/// Describes an alternate setting for an interface.
+#[derive(Debug, PartialEq, Eq)]
+pub struct InterfaceDescriptor {
+    interface_number: u8,
+    setting_number: u8,
+    interface_class: u8,
+    interface_sub_class: u8,
+    interface_protocol: u8,
+    description_index: Option<u8>
+}
+
+impl InterfaceDescriptor {
+    /// Returns the interface number.
+    pub fn interface_number(&self) -> u8 {
+        self.interface_number
+    }
+
+    /// Returns the alternate setting number.
+    pub fn setting_number(&self) -> u8 {
+        self.setting_number
+    }
+
+    /// Returns the interface's class code.
+    pub fn class_code(&self) -> u8 {
+        self.interface_class
+    }
+
+    /// Returns the interface's sub class code.
+    pub fn sub_class_code(&self) -> u8 {
+        self.interface_sub_class
+    }
+
+    /// Returns the interface's protocol code.
+    pub fn protocol_code(&self) -> u8 {
+        self.interface_protocol
+    }
+
+    /// Returns the index of the string descriptor that describes this interface.
+    pub fn description_string_index(&self) -> Option<u8> {
+        self.description_index
+    }
+}
+
+
+#[doc(hidden)]
+pub fn from_libusb(descriptor: &::libusb::libusb_interface_descriptor) -> InterfaceDescriptor {
+    InterfaceDescriptor {
+        interface_number:    descriptor.bInterfaceNumber,
+        setting_number:      descriptor.bAlternateSetting,
+        interface_class:     descriptor.bInterfaceClass,
+        interface_sub_class: descriptor.bInterfaceSubClass,
+        interface_protocol:  descriptor.bInterfaceProtocol,
+        description_index:   match descriptor.iInterface {
+            0 => None,
+            n => Some(n)
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn it_has_interface_number() {
+        assert_eq!(42, ::interface_descriptor::from_libusb(&interface_descriptor!(bInterfaceNumber: 42)).interface_number());
+    }
+
+    #[test]
+    fn it_has_setting_number() {
+        assert_eq!(42, ::interface_descriptor::from_libusb(&interface_descriptor!(bAlternateSetting: 42)).setting_number());
+    }
+
+    #[test]
+    fn it_has_class_code() {
+        assert_eq!(42, ::interface_descriptor::from_libusb(&interface_descriptor!(bInterfaceClass: 42)).class_code());
+    }
+
+    #[test]
+    fn it_has_sub_class_code() {
+        assert_eq!(42, ::interface_descriptor::from_libusb(&interface_descriptor!(bInterfaceSubClass: 42)).sub_class_code());
+    }
+
+    #[test]
+    fn it_has_protocol_code() {
+        assert_eq!(42, ::interface_descriptor::from_libusb(&interface_descriptor!(bInterfaceProtocol: 42)).protocol_code());
+    }
+
+    #[test]
+    fn it_has_description_string_index() {
+        assert_eq!(Some(42), ::interface_descriptor::from_libusb(&interface_descriptor!(iInterface: 42)).description_string_index());
+    }
+
+    #[test]
+    fn it_handles_missing_description_string_index() {
+        assert_eq!(None, ::interface_descriptor::from_libusb(&interface_descriptor!(iInterface: 0)).description_string_index());
+    }
+}